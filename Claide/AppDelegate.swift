@@ -288,6 +288,59 @@ final class AppDelegate: NSObject, NSApplicationDelegate {
 
         menu.addItem(.separator())
 
+        let scrollItems: [(String, Selector, Int)] = [
+            ("Scroll to Top", #selector(scrollToTop), NSHomeFunctionKey),
+            ("Scroll to Bottom", #selector(scrollToBottom), NSEndFunctionKey),
+            ("Page Up", #selector(scrollPageUp), NSPageUpFunctionKey),
+            ("Page Down", #selector(scrollPageDown), NSPageDownFunctionKey),
+        ]
+        for (title, action, key) in scrollItems {
+            let item = NSMenuItem(title: title, action: action, keyEquivalent: String(Character(UnicodeScalar(key)!)))
+            item.target = self
+            menu.addItem(item)
+        }
+
+        let promptItems: [(String, Selector, Int)] = [
+            ("Previous Prompt", #selector(jumpToPreviousPrompt), NSUpArrowFunctionKey),
+            ("Next Prompt", #selector(jumpToNextPrompt), NSDownArrowFunctionKey),
+        ]
+        for (title, action, key) in promptItems {
+            let item = NSMenuItem(title: title, action: action, keyEquivalent: String(Character(UnicodeScalar(key)!)))
+            item.keyEquivalentModifierMask = [.command, .shift]
+            item.target = self
+            menu.addItem(item)
+        }
+
+        menu.addItem(.separator())
+
+        let clear = NSMenuItem(title: "Clear Scrollback", action: #selector(clearScrollback), keyEquivalent: "k")
+        clear.target = self
+        menu.addItem(clear)
+
+        let softReset = NSMenuItem(title: "Soft Reset", action: #selector(softResetTerminal), keyEquivalent: "")
+        softReset.target = self
+        menu.addItem(softReset)
+
+        let reset = NSMenuItem(title: "Reset Terminal", action: #selector(resetTerminal), keyEquivalent: "")
+        reset.target = self
+        menu.addItem(reset)
+
+        let restart = NSMenuItem(title: "Restart Session", action: #selector(restartSession), keyEquivalent: "")
+        restart.target = self
+        menu.addItem(restart)
+
+        menu.addItem(.separator())
+
+        let interrupt = NSMenuItem(title: "Interrupt Process", action: #selector(interruptForegroundProcess), keyEquivalent: "")
+        interrupt.target = self
+        menu.addItem(interrupt)
+
+        let killItem = NSMenuItem(title: "Kill Process", action: #selector(killForegroundProcess), keyEquivalent: "")
+        killItem.target = self
+        menu.addItem(killItem)
+
+        menu.addItem(.separator())
+
         for i in 1...9 {
             let item = NSMenuItem(
                 title: "Tab \(i)",
@@ -346,6 +399,29 @@ final class AppDelegate: NSObject, NSApplicationDelegate {
                 case "0":
                     self.activeTerminalView?.resetFontSize()
                     return nil
+                case "k":
+                    self.clearScrollback()
+                    return nil
+                default:
+                    break
+                }
+            }
+
+            // Scrollback navigation. Home/End/Page keys carry the .function flag.
+            if flags.subtracting(.function) == .command {
+                switch event.keyCode {
+                case 115: // Home
+                    self.activeTerminalView?.scrollToTop()
+                    return nil
+                case 119: // End
+                    self.activeTerminalView?.scrollToBottom()
+                    return nil
+                case 116: // Page Up
+                    self.activeTerminalView?.scrollPageUp()
+                    return nil
+                case 121: // Page Down
+                    self.activeTerminalView?.scrollPageDown()
+                    return nil
                 default:
                     break
                 }
@@ -386,7 +462,10 @@ final class AppDelegate: NSObject, NSApplicationDelegate {
                 default:
                     break
                 }
+            }
 
+            // Arrow keys carry the .numericPad and .function flags.
+            if flags.subtracting([.numericPad, .function]) == [.command, .shift] {
                 switch event.keyCode {
                 case 123: // Left arrow
                     self.moveActiveTabLeft()
@@ -394,6 +473,12 @@ final class AppDelegate: NSObject, NSApplicationDelegate {
                 case 124: // Right arrow
                     self.moveActiveTabRight()
                     return nil
+                case 126: // Up arrow
+                    self.jumpToPreviousPrompt()
+                    return nil
+                case 125: // Down arrow
+                    self.jumpToNextPrompt()
+                    return nil
                 default:
                     break
                 }
@@ -468,6 +553,54 @@ final class AppDelegate: NSObject, NSApplicationDelegate {
         activeTerminalView?.resetFontSize()
     }
 
+    @objc private func scrollToTop() {
+        activeTerminalView?.scrollToTop()
+    }
+
+    @objc private func scrollToBottom() {
+        activeTerminalView?.scrollToBottom()
+    }
+
+    @objc private func scrollPageUp() {
+        activeTerminalView?.scrollPageUp()
+    }
+
+    @objc private func scrollPageDown() {
+        activeTerminalView?.scrollPageDown()
+    }
+
+    @objc private func jumpToPreviousPrompt() {
+        activeTerminalView?.jumpToPrompt(-1)
+    }
+
+    @objc private func jumpToNextPrompt() {
+        activeTerminalView?.jumpToPrompt(1)
+    }
+
+    @objc private func clearScrollback() {
+        activeTerminalView?.clearScrollback()
+    }
+
+    @objc private func softResetTerminal() {
+        activeTerminalView?.softResetTerminal()
+    }
+
+    @objc private func resetTerminal() {
+        activeTerminalView?.resetTerminal()
+    }
+
+    @objc private func restartSession() {
+        activeTabManager?.restartActivePane()
+    }
+
+    @objc private func interruptForegroundProcess() {
+        activeTabManager?.activeTab?.viewModel.signalForeground(SIGINT)
+    }
+
+    @objc private func killForegroundProcess() {
+        activeTabManager?.activeTab?.viewModel.signalForeground(SIGKILL)
+    }
+
     @objc private func showCommandPalette() {
         toggleCommandPalette()
     }
@@ -26,13 +26,29 @@ struct ClaideApp: App {
             "confirmBeforeClosing": true,
             "quitWhenLastWindowCloses": false,
             "bellStyle": "visual",
+            "notifyOnLongCommands": false,
+            "longCommandThreshold": 30.0,
+            "respawnPolicy": "never",
+            "respawnDelay": 1.0,
             // Terminal
+            "shellIntegration": "detect",
+            "terminalType": "xterm-ghostty",
+            "scrollToBottomOnKeystroke": true,
             "copyOnSelect": false,
             "pasteOnRightClick": false,
+            "trimTrailingSpaces": true,
             // Appearance
             "terminalFontSize": 14.0,
             "uiFontSize": 12.0,
             "terminalColorScheme": "hexed",
+            "matchSystemAppearance": false,
+            "terminalColorSchemeLight": "iTerm2 Solarized Light",
+            "terminalColorSchemeDark": "hexed",
+            "boldIsBright": false,
+            "minimumContrast": 1.0,
+            "cursorColor": "",
+            "cursorStyle": "",
+            "cursorBlink": "",
             "paneFocusIndicator": true,
             "dimUnfocusedPanes": true,
             // Hotkey Window
@@ -71,6 +71,13 @@ enum CommandPaletteRegistry {
             action: { @MainActor in tabManager.closeActivePane() }
         ))
 
+        items.append(CommandPaletteItem(
+            id: "restart-session",
+            title: "Restart Session",
+            icon: "arrow.clockwise",
+            action: { @MainActor in tabManager.restartActivePane() }
+        ))
+
         if let toggleSidebar {
             items.append(CommandPaletteItem(
                 id: "toggle-sidebar",
@@ -110,6 +110,7 @@ final class GhosttyApp {
 
         // Read terminal colors from finalized config so chrome matches.
         readConfigColors(config)
+        ActiveColorScheme.shared.update(systemIsDark: Self.systemIsDark)
 
         // Log any config diagnostics
         let diagCount = ghostty_config_diagnostics_count(config)
@@ -142,6 +143,7 @@ final class GhosttyApp {
 
         self.app = newApp
         logger.info("Ghostty engine started")
+        applyColorSchemeMode()
 
         NotificationCenter.default.addObserver(
             self,
@@ -150,6 +152,7 @@ final class GhosttyApp {
             object: nil
         )
         observeGhosttySettings()
+        observeSystemAppearance()
     }
 
     @objc private func keyboardLayoutChanged(_ notification: Notification) {
@@ -195,14 +198,67 @@ final class GhosttyApp {
             configSet(config, key: "scrollback-limit", value: String(scrollback))
         }
 
+        // Ghostty injects its bundled integration scripts (Resources/ghostty/
+        // shell-integration) at spawn, providing OSC 7 and OSC 133 marks.
+        let shellIntegration = defaults.string(forKey: "shellIntegration") ?? ""
+        if !shellIntegration.isEmpty {
+            configSet(config, key: "shell-integration", value: shellIntegration)
+        }
+
+        // Ghostty exports this as TERM. xterm-ghostty's terminfo ships in the
+        // app bundle but is often missing on remote hosts reached over SSH.
+        let terminalType = defaults.string(forKey: "terminalType") ?? ""
+        if !terminalType.isEmpty {
+            configSet(config, key: "term", value: terminalType)
+        }
+
+        if defaults.object(forKey: "scrollToBottomOnKeystroke") != nil {
+            let onKeystroke = defaults.bool(forKey: "scrollToBottomOnKeystroke")
+            configSet(config, key: "scroll-to-bottom", value: onKeystroke ? "keystroke" : "no-keystroke")
+        }
+
         if defaults.object(forKey: "copyOnSelect") != nil {
             let copyOnSelect = defaults.bool(forKey: "copyOnSelect")
             configSet(config, key: "copy-on-select", value: copyOnSelect ? "true" : "false")
         }
 
+        if defaults.object(forKey: "trimTrailingSpaces") != nil {
+            let trim = defaults.bool(forKey: "trimTrailingSpaces")
+            configSet(config, key: "clipboard-trim-trailing-spaces", value: trim ? "true" : "false")
+        }
+
+        // Ghostty 1.2 replaced bold-is-bright with bold-color. When off, leave
+        // the key alone so a bold-color from the user's Ghostty config applies.
+        if defaults.bool(forKey: "boldIsBright") {
+            configSet(config, key: "bold-color", value: "bright")
+        }
+
+        let minimumContrast = defaults.double(forKey: "minimumContrast")
+        if minimumContrast >= 1 {
+            configSet(config, key: "minimum-contrast", value: String(minimumContrast))
+        }
+
         // Terminal color theme
-        let schemeName = defaults.string(forKey: "terminalColorScheme") ?? "hexed"
+        let schemeName = ActiveColorScheme.resolve(defaults: defaults, systemIsDark: Self.systemIsDark)
         applyColorScheme(schemeName, to: config)
+
+        // Cursor color overrides the theme; OSC 12/112 from programs still apply on top.
+        let cursorColor = defaults.string(forKey: "cursorColor") ?? ""
+        if !cursorColor.isEmpty {
+            configSet(config, key: "cursor-color", value: cursorColor)
+        }
+
+        let cursorStyle = defaults.string(forKey: "cursorStyle") ?? ""
+        if !cursorStyle.isEmpty {
+            configSet(config, key: "cursor-style", value: cursorStyle)
+        }
+
+        // Empty means unset: blink by default, but let DECSCUSR and
+        // DEC mode 12 from programs choose blinking vs. steady.
+        let cursorBlink = defaults.string(forKey: "cursorBlink") ?? ""
+        if !cursorBlink.isEmpty {
+            configSet(config, key: "cursor-style-blink", value: cursorBlink)
+        }
     }
 
     /// Set terminal colors by scheme name. "hexed" uses individual palette keys;
@@ -231,8 +287,10 @@ final class GhosttyApp {
         ghostty_config_finalize(config)
 
         readConfigColors(config)
+        ActiveColorScheme.shared.update(systemIsDark: Self.systemIsDark)
         ghostty_app_update_config(app, config)
         // ghostty_app_update_config takes ownership — do NOT free config
+        applyColorSchemeMode()
     }
 
     private var settingsObservation: Any?
@@ -247,6 +305,40 @@ final class GhosttyApp {
         }
     }
 
+    // MARK: - System Appearance
+
+    private var appearanceObservation: NSKeyValueObservation?
+
+    /// Whether macOS is currently in Dark Mode. NSApp's appearance is never
+    /// overridden (only window appearances are), so this tracks the system.
+    static var systemIsDark: Bool {
+        NSApp.effectiveAppearance.bestMatch(from: [.aqua, .darkAqua]) == .darkAqua
+    }
+
+    private func observeSystemAppearance() {
+        appearanceObservation = NSApp.observe(\.effectiveAppearance, options: [.initial, .new]) { [weak self] _, _ in
+            Task { @MainActor in self?.systemAppearanceChanged() }
+        }
+    }
+
+    /// A light/dark switch only changes the terminal when its schemes follow
+    /// the system; the reload then updates colors and the reported mode.
+    private func systemAppearanceChanged() {
+        if UserDefaults.standard.bool(forKey: "matchSystemAppearance") {
+            reloadConfig()
+        }
+    }
+
+    /// Push the active scheme's light/dark mode to Ghostty's conditional
+    /// config and to DEC mode 2031 reports in every surface.
+    private func applyColorSchemeMode() {
+        if let app {
+            let isDark = ActiveColorScheme.shared.isDark
+            ghostty_app_set_color_scheme(app, isDark ? GHOSTTY_COLOR_SCHEME_DARK : GHOSTTY_COLOR_SCHEME_LIGHT)
+        }
+        GhosttyTerminalView.syncColorSchemeForAll()
+    }
+
     // MARK: - Config Colors
 
     /// Read background and foreground from Ghostty's finalized config.
@@ -315,7 +407,11 @@ final class GhosttyApp {
 
             case GHOSTTY_ACTION_SHOW_CHILD_EXITED:
                 let exitCode = Int32(action.action.child_exited.exit_code)
-                DispatchQueue.main.async { view.onChildExit?(exitCode) }
+                // Drop the exit if the pane was restarted before this ran.
+                DispatchQueue.main.async {
+                    guard view.surface == surface else { return }
+                    view.onChildExit?(exitCode)
+                }
                 return true
 
             case GHOSTTY_ACTION_PROGRESS_REPORT:
@@ -326,7 +422,18 @@ final class GhosttyApp {
                 return true
 
             case GHOSTTY_ACTION_COLOR_CHANGE:
-                // Terminal-initiated color change (e.g. OSC 10/11)
+                // Terminal-initiated color change (OSC 4/10/11/12). Ghostty already
+                // renders with the new color; the host only updates its chrome.
+                let change = action.action.color_change
+                let color = RGB(change.r, change.g, change.b)
+                let target: TerminalColorTarget
+                switch change.kind {
+                case GHOSTTY_ACTION_COLOR_KIND_FOREGROUND: target = .foreground
+                case GHOSTTY_ACTION_COLOR_KIND_BACKGROUND: target = .background
+                case GHOSTTY_ACTION_COLOR_KIND_CURSOR: target = .cursor
+                default: target = .palette(Int(change.kind.rawValue))
+                }
+                DispatchQueue.main.async { view.onColorChange?(target, color) }
                 return true
 
             case GHOSTTY_ACTION_DESKTOP_NOTIFICATION:
@@ -407,7 +514,7 @@ final class GhosttyApp {
 
     // MARK: - Desktop Notifications
 
-    private static func postDesktopNotification(title: String, body: String) {
+    static func postDesktopNotification(title: String, body: String) {
         let content = UNMutableNotificationContent()
         content.title = title
         content.body = body
@@ -512,7 +619,10 @@ final class GhosttyApp {
     ) {
         guard let userdata else { return }
         let view = Unmanaged<GhosttyTerminalView>.fromOpaque(userdata).takeUnretainedValue()
+        // Called from within ghostty_app_tick, which runs on the main queue.
+        let surface = view.surface
         DispatchQueue.main.async {
+            guard view.surface == surface else { return }
             view.onChildExit?(0)
         }
     }
@@ -5,6 +5,15 @@ import AppKit
 import GhosttyKit
 import os
 
+/// Which terminal color a program changed at runtime.
+enum TerminalColorTarget: Equatable {
+    case foreground
+    case background
+    case cursor
+    /// Indexed palette entry (0-255), set via OSC 4.
+    case palette(Int)
+}
+
 /// Terminal view backed by Ghostty's Metal renderer and VTE engine.
 ///
 /// Ghostty owns the CAMetalLayer, PTY, and parsing. This view forwards input
@@ -49,6 +58,10 @@ final class GhosttyTerminalView: NSView {
     var onFocused: (() -> Void)?
     var onProgressReport: ((UInt8, Int32) -> Void)?
 
+    /// Fired when a program changes a palette entry or default color
+    /// (OSC 4/10/11/12), including resets back to the configured value.
+    var onColorChange: ((TerminalColorTarget, RGB) -> Void)?
+
     /// When set, keyboard events are offered to this closure first.
     /// Return `true` to consume the event (prevents Ghostty from seeing it).
     var inputInterceptor: ((NSEvent) -> Bool)?
@@ -175,6 +188,7 @@ final class GhosttyTerminalView: NSView {
         }
 
         registerSurface()
+        syncColorScheme()
         updateTrackingAreas()
         setupDragAndDrop()
         startDisplayRefresh()
@@ -302,6 +316,22 @@ final class GhosttyTerminalView: NSView {
         }
     }
 
+    // MARK: - Color Scheme
+
+    /// Tell Ghostty whether the active scheme is light or dark. Programs that
+    /// enabled DEC mode 2031 receive a color scheme report when it changes.
+    func syncColorScheme() {
+        guard let surface else { return }
+        let scheme = ActiveColorScheme.shared.isDark ? GHOSTTY_COLOR_SCHEME_DARK : GHOSTTY_COLOR_SCHEME_LIGHT
+        ghostty_surface_set_color_scheme(surface, scheme)
+    }
+
+    static func syncColorSchemeForAll() {
+        for view in surfaceRegistry.values {
+            view.syncColorScheme()
+        }
+    }
+
     // MARK: - Sizing
 
     override func setFrameSize(_ newSize: NSSize) {
@@ -315,6 +345,15 @@ final class GhosttyTerminalView: NSView {
 
     override func viewDidChangeBackingProperties() {
         super.viewDidChangeBackingProperties()
+        guard let surface, window != nil else { return }
+
+        applyContentScale()
+
+        let scaled = convertToBacking(contentSize)
+        ghostty_surface_set_size(surface, UInt32(scaled.width), UInt32(scaled.height))
+    }
+
+    private func applyContentScale() {
         guard let surface, let window else { return }
 
         layer?.contentsScale = window.backingScaleFactor
@@ -323,9 +362,16 @@ final class GhosttyTerminalView: NSView {
         let xScale = fbFrame.size.width / frame.size.width
         let yScale = fbFrame.size.height / frame.size.height
         ghostty_surface_set_content_scale(surface, xScale, yScale)
+    }
 
-        let scaled = convertToBacking(contentSize)
-        ghostty_surface_set_size(surface, UInt32(scaled.width), UInt32(scaled.height))
+    /// Push scale, size, and display to a surface created in a view that is
+    /// already laid out (e.g. after a restart), where no resize or
+    /// window-move callback will fire to do it.
+    func syncSurfaceGeometry() {
+        guard surface != nil, window != nil else { return }
+        applyContentScale()
+        setFrameSize(frame.size)
+        syncDisplayId()
     }
 
     /// Fire `onGridResize` when the terminal grid dimensions change.
@@ -386,18 +432,89 @@ final class GhosttyTerminalView: NSView {
         _ = bindingAction("reset_font_size")
     }
 
+    func scrollToTop() {
+        _ = bindingAction("scroll_to_top")
+    }
+
+    func scrollToBottom() {
+        _ = bindingAction("scroll_to_bottom")
+    }
+
+    func scrollPageUp() {
+        _ = bindingAction("scroll_page_up")
+    }
+
+    func scrollPageDown() {
+        _ = bindingAction("scroll_page_down")
+    }
+
+    /// Scroll to a prompt recorded by shell integration (OSC 133).
+    /// Negative values move to earlier prompts, positive to later ones.
+    func jumpToPrompt(_ delta: Int) {
+        _ = bindingAction("jump_to_prompt:\(delta)")
+    }
+
+    /// Erase the scrollback and screen while keeping the shell running.
+    /// Ghostty redraws the prompt afterwards when shell integration is active.
+    func clearScrollback() {
+        _ = bindingAction("clear_screen")
+    }
+
+    /// Full terminal reset (RIS): restores modes, charsets, and colors to
+    /// recover from garbled output without restarting the shell.
+    func resetTerminal() {
+        _ = bindingAction("reset")
+    }
+
+    /// Soft reset (DECSTR): restores modes, charsets, and cursor state but
+    /// keeps the screen contents. Fed to the emulator directly, not the shell.
+    func softResetTerminal() {
+        feedOutput(Self.softResetSequence)
+    }
+
+    private static let softResetSequence = Data("\u{1b}[!p".utf8)
+
     // MARK: - Mouse Cursor
 
+    /// Apply a pointer shape requested by Ghostty (mouse reporting changes or
+    /// an application's OSC 22). Shapes without an AppKit equivalent fall back
+    /// to the arrow.
     func updateMouseCursor(_ shape: ghostty_action_mouse_shape_e) {
         switch shape {
         case GHOSTTY_MOUSE_SHAPE_DEFAULT:
             NSCursor.arrow.set()
         case GHOSTTY_MOUSE_SHAPE_TEXT:
             NSCursor.iBeam.set()
+        case GHOSTTY_MOUSE_SHAPE_VERTICAL_TEXT:
+            NSCursor.iBeamCursorForVerticalLayout.set()
         case GHOSTTY_MOUSE_SHAPE_POINTER:
             NSCursor.pointingHand.set()
         case GHOSTTY_MOUSE_SHAPE_CROSSHAIR:
             NSCursor.crosshair.set()
+        case GHOSTTY_MOUSE_SHAPE_CONTEXT_MENU:
+            NSCursor.contextualMenu.set()
+        case GHOSTTY_MOUSE_SHAPE_ALIAS:
+            NSCursor.dragLink.set()
+        case GHOSTTY_MOUSE_SHAPE_COPY:
+            NSCursor.dragCopy.set()
+        case GHOSTTY_MOUSE_SHAPE_NOT_ALLOWED, GHOSTTY_MOUSE_SHAPE_NO_DROP:
+            NSCursor.operationNotAllowed.set()
+        case GHOSTTY_MOUSE_SHAPE_GRAB:
+            NSCursor.openHand.set()
+        case GHOSTTY_MOUSE_SHAPE_GRABBING:
+            NSCursor.closedHand.set()
+        case GHOSTTY_MOUSE_SHAPE_N_RESIZE:
+            NSCursor.resizeUp.set()
+        case GHOSTTY_MOUSE_SHAPE_S_RESIZE:
+            NSCursor.resizeDown.set()
+        case GHOSTTY_MOUSE_SHAPE_E_RESIZE:
+            NSCursor.resizeRight.set()
+        case GHOSTTY_MOUSE_SHAPE_W_RESIZE:
+            NSCursor.resizeLeft.set()
+        case GHOSTTY_MOUSE_SHAPE_NS_RESIZE, GHOSTTY_MOUSE_SHAPE_ROW_RESIZE:
+            NSCursor.resizeUpDown.set()
+        case GHOSTTY_MOUSE_SHAPE_EW_RESIZE, GHOSTTY_MOUSE_SHAPE_COL_RESIZE:
+            NSCursor.resizeLeftRight.set()
         default:
             NSCursor.arrow.set()
         }
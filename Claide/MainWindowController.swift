@@ -26,7 +26,7 @@ final class MainWindowController: NSWindowController, NSWindowDelegate {
         window.isMovableByWindowBackground = false
         window.tabbingMode = .disallowed
         window.backgroundColor = TerminalTheme.background
-        let initialScheme = UserDefaults.standard.string(forKey: "terminalColorScheme") ?? "hexed"
+        let initialScheme = ActiveColorScheme.shared.name
         let initialBG = ChromeColorScheme.named(initialScheme).background
         let initialBrightness = (Int(initialBG.r) * 299 + Int(initialBG.g) * 587 + Int(initialBG.b) * 114) / 1000
         window.appearance = NSAppearance(named: initialBrightness > 128 ? .aqua : .darkAqua)
@@ -44,7 +44,7 @@ final class MainWindowController: NSWindowController, NSWindowDelegate {
         tabManager.onColorSchemeApplied = { [weak self] in
             guard let window = self?.window else { return }
             window.backgroundColor = TerminalTheme.background
-            let schemeName = UserDefaults.standard.string(forKey: "terminalColorScheme") ?? "hexed"
+            let schemeName = ActiveColorScheme.shared.name
             let bg = ChromeColorScheme.named(schemeName).background
             let brightness = (Int(bg.r) * 299 + Int(bg.g) * 587 + Int(bg.b) * 114) / 1000
             window.appearance = NSAppearance(named: brightness > 128 ? .aqua : .darkAqua)
@@ -92,7 +92,8 @@ final class MainWindowController: NSWindowController, NSWindowDelegate {
     // MARK: - NSWindowDelegate
 
     func windowShouldClose(_ sender: NSWindow) -> Bool {
-        guard UserDefaults.standard.bool(forKey: "confirmBeforeClosing") else {
+        guard UserDefaults.standard.bool(forKey: "confirmBeforeClosing"),
+              tabManager.hasRunningProcess else {
             return true
         }
         let alert = NSAlert()
@@ -0,0 +1,57 @@
+// ABOUTME: Observable name of the terminal color scheme currently in effect.
+// ABOUTME: Resolves the light/dark pair and the light/dark mode reported to programs.
+
+import Foundation
+import Observation
+
+/// The terminal scheme in effect, derived from settings without changing them.
+/// GhosttyApp updates it after each config rebuild, so views that read `name`
+/// re-render once the engine colors they draw from are current.
+@MainActor @Observable
+final class ActiveColorScheme {
+    static let shared = ActiveColorScheme()
+
+    private(set) var name: String = ActiveColorScheme.resolve(systemIsDark: false)
+    /// Light/dark mode reported to programs via DEC mode 2031.
+    private(set) var isDark = true
+
+    private init() {}
+
+    /// Must run after the engine colors are read, since non-Hexed schemes
+    /// take their background from them.
+    func update(systemIsDark: Bool) {
+        let resolved = Self.resolve(systemIsDark: systemIsDark)
+        if resolved != name {
+            name = resolved
+        }
+        let dark = Self.reportsDark(
+            matchingSystem: UserDefaults.standard.bool(forKey: "matchSystemAppearance"),
+            systemIsDark: systemIsDark,
+            background: ChromeColorScheme.named(resolved).background
+        )
+        if dark != isDark {
+            isDark = dark
+        }
+    }
+
+    /// The light or dark pick while "Match system appearance" is on,
+    /// otherwise the scheme the user chose.
+    nonisolated static func resolve(defaults: UserDefaults = .standard, systemIsDark: Bool) -> String {
+        if defaults.bool(forKey: "matchSystemAppearance") {
+            let key = systemIsDark ? "terminalColorSchemeDark" : "terminalColorSchemeLight"
+            if let name = defaults.string(forKey: key), !name.isEmpty {
+                return name
+            }
+        }
+        return defaults.string(forKey: "terminalColorScheme") ?? "hexed"
+    }
+
+    /// While matching the system the schemes follow macOS, so report its mode.
+    /// Otherwise report what the terminal actually shows, judged by background
+    /// brightness the same way the window chrome picks aqua or darkAqua.
+    nonisolated static func reportsDark(matchingSystem: Bool, systemIsDark: Bool, background: RGB) -> Bool {
+        if matchingSystem { return systemIsDark }
+        let brightness = (Int(background.r) * 299 + Int(background.g) * 587 + Int(background.b) * 114) / 1000
+        return brightness <= 128
+    }
+}
@@ -33,6 +33,19 @@ struct ChromeColorScheme: Identifiable, Hashable {
         )
     }
 
+    /// Copy of this scheme with the default colors replaced by runtime
+    /// overrides (e.g. a program sending OSC 10/11). Nil keeps the scheme's color.
+    func overriding(foreground: RGB?, background: RGB?) -> ChromeColorScheme {
+        ChromeColorScheme(
+            id: id,
+            name: name,
+            ansi: ansi,
+            foreground: foreground ?? self.foreground,
+            background: background ?? self.background,
+            ghosttyThemeName: ghosttyThemeName
+        )
+    }
+
     /// Sorted list of Ghostty theme filenames from the app bundle.
     static let ghosttyThemeNames: [String] = {
         guard let themesURL = Bundle.main.url(
@@ -0,0 +1,42 @@
+// ABOUTME: Shell flavor detection from an executable path.
+// ABOUTME: Decides whether Ghostty's automatic shell integration can apply.
+
+import Foundation
+
+/// Shell flavor inferred from the executable name.
+enum ShellKind: String, CaseIterable {
+    case zsh, bash, fish, elvish, nushell, other
+
+    /// Classify by executable name, ignoring the directory and a login "-" prefix.
+    init(executable: String) {
+        var name = (executable as NSString).lastPathComponent
+        if name.hasPrefix("-") { name.removeFirst() }
+        switch name {
+        case "zsh": self = .zsh
+        case "bash": self = .bash
+        case "fish": self = .fish
+        case "elvish": self = .elvish
+        case "nu": self = .nushell
+        default: self = .other
+        }
+    }
+
+    /// Whether Ghostty ships integration scripts for this shell.
+    var supportsIntegration: Bool {
+        switch self {
+        case .zsh, .bash, .fish, .elvish: true
+        case .nushell, .other: false
+        }
+    }
+
+    var displayName: String {
+        switch self {
+        case .zsh: "Zsh"
+        case .bash: "Bash"
+        case .fish: "Fish"
+        case .elvish: "Elvish"
+        case .nushell: "Nushell"
+        case .other: "Unknown"
+        }
+    }
+}
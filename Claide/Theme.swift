@@ -446,7 +446,7 @@ struct SectionHeader: View {
 
 /// Panel container with thin border, dark background
 struct PanelView<Content: View>: View {
-    @AppStorage("terminalColorScheme") private var schemeName: String = "hexed"
+    private var schemeName: String { ActiveColorScheme.shared.name }
     @ViewBuilder let content: () -> Content
 
     var body: some View {
@@ -64,6 +64,8 @@ final class TerminalViewModel {
     var tabColor: TabColor?
     var isRunning: Bool = false
     var profile: TerminalProfile = .default
+    /// Stable identifier for this pane's session, exported as TERM_SESSION_ID.
+    let sessionID = UUID()
 
     /// When true, foreground process tracking is suppressed.
     /// Used for tmux panes where the local shell is a dummy process.
@@ -80,39 +82,116 @@ final class TerminalViewModel {
     var currentDirectory: String?
     var executablePath: String?
 
+    /// Default colors set by the running program (OSC 10/11), nil until changed.
+    var foregroundOverride: RGB?
+    var backgroundOverride: RGB?
+
+    /// Chrome colors for this pane: the profile's scheme with any
+    /// program-set default colors applied on top.
+    var chromeColorScheme: ChromeColorScheme {
+        ChromeColorScheme.named(profile.resolvedColorScheme)
+            .overriding(foreground: foregroundOverride, background: backgroundOverride)
+    }
+
     private var shellPid: pid_t = 0
     private var shellExecutable: String?
     private var trackingTask: Task<Void, Never>?
+    /// Set once the shell reports its directory via OSC 7; disables the libproc fallback.
+    private var reportsDirectory = false
+    /// Set while the running program has supplied its own title (OSC 0/2).
+    private var hasProgramTitle = false
+
+    /// When the current foreground command was first seen, nil while idle.
+    private(set) var commandStartedAt: Date?
+    private var commandName: String?
+
+    /// Fired when a foreground command exits, with its name and how long it ran.
+    var onCommandFinished: ((String, TimeInterval) -> Void)?
 
-    func processStarted(executable: String, args: [String]) {
+    /// When the shell was launched, used to detect shells that exit right away.
+    private(set) var launchedAt: Date?
+
+    func processStarted(executable: String, args: [String], at now: Date = Date()) {
+        launchedAt = now
         executablePath = executable
         shellExecutable = executable
         let name = (executable as NSString).lastPathComponent
         title = ([name] + args).joined(separator: " ")
     }
 
+    /// Exit status of the shell once it has exited. A failure code wins over
+    /// the 0 that Ghostty's close-surface notification reports.
+    private(set) var exitCode: Int32?
+
     func processTerminated(exitCode: Int32?) {
+        if let exitCode, exitCode != 0 || self.exitCode == nil {
+            self.exitCode = exitCode
+        }
         isRunning = false
         stopTrackingForeground()
         title = "\(title) (exited: \(exitCode ?? -1))"
     }
 
     func titleChanged(_ newTitle: String) {
-        title = newTitle
+        hasProgramTitle = !newTitle.isEmpty
+        if hasProgramTitle {
+            title = newTitle
+        } else {
+            refreshCompositeTitle()
+        }
+    }
+
+    /// Title synthesized from the foreground process and directory, used until
+    /// the program sets its own so tabs never show a stale or blank title.
+    private func refreshCompositeTitle() {
+        guard !hasProgramTitle, !isTmuxPane,
+              let composite = Self.compositeTitle(executable: executablePath, directory: currentDirectory),
+              composite != title else { return }
+        title = composite
+    }
+
+    /// "process — directory", with the home directory shown as "~".
+    nonisolated static func compositeTitle(executable: String?, directory: String?) -> String? {
+        let process = executable.map { ($0 as NSString).lastPathComponent }
+        let folder = directory.map { dir in
+            dir == NSHomeDirectory() ? "~" : (dir as NSString).lastPathComponent
+        }
+        switch (process, folder) {
+        case let (process?, folder?): return "\(process) — \(folder)"
+        case let (process?, nil): return process
+        case let (nil, folder?): return folder
+        case (nil, nil): return nil
+        }
     }
 
     func directoryChanged(_ directory: String?) {
-        // OSC 7 reports a file:// URL (e.g. "file://hostname/Users/hex/path").
-        // Extract the filesystem path from the URL.
         guard let raw = directory else {
             currentDirectory = nil
             return
         }
+        reportsDirectory = true
+        // Ghostty drops OSC 7 reports from other hosts and percent-decodes the
+        // path before sending GHOSTTY_ACTION_PWD, so this is normally a plain path.
         if raw.hasPrefix("file://"), let url = URL(string: raw) {
             currentDirectory = url.path
         } else {
             currentDirectory = raw
         }
+        refreshCompositeTitle()
+    }
+
+    func colorChanged(_ target: TerminalColorTarget, to color: RGB) {
+        // A reset (OSC 110/111) arrives as the scheme's own color; treat a
+        // match as no override so later scheme changes show through.
+        let scheme = ChromeColorScheme.named(profile.resolvedColorScheme)
+        switch target {
+        case .foreground:
+            foregroundOverride = color == scheme.foreground ? nil : color
+        case .background:
+            backgroundOverride = color == scheme.background ? nil : color
+        case .cursor, .palette:
+            break
+        }
     }
 
     func progressReported(state: UInt8, progress: Int32) {
@@ -160,11 +239,57 @@ final class TerminalViewModel {
         let commandRunning = childPath != nil
         if commandRunning != isRunning {
             isRunning = commandRunning
+            commandRunningChanged(commandRunning, executable: childPath)
         }
         let newPath = childPath ?? shellExecutable
         if newPath != executablePath {
             executablePath = newPath
         }
+        // Shells without OSC 7 integration never report their directory;
+        // read the shell's cwd from the kernel instead.
+        if !reportsDirectory, let cwd = Self.workingDirectory(for: shellPid), cwd != currentDirectory {
+            currentDirectory = cwd
+        }
+        refreshCompositeTitle()
+    }
+
+    /// Record command start/finish transitions detected by foreground polling.
+    /// Timing is approximate to the 1s polling interval.
+    func commandRunningChanged(_ running: Bool, executable: String?, at now: Date = Date()) {
+        if running {
+            commandStartedAt = now
+            commandName = executable.map { ($0 as NSString).lastPathComponent }
+        } else if let start = commandStartedAt {
+            if let name = commandName {
+                onCommandFinished?(name, now.timeIntervalSince(start))
+            }
+            commandStartedAt = nil
+            commandName = nil
+        }
+    }
+
+    // MARK: - Signals
+
+    /// Send a signal to the terminal's foreground process group, like Ctrl-C
+    /// does, even when the program has the terminal in raw mode. Background
+    /// jobs are untouched. Returns false when the shell itself is in the
+    /// foreground or the signal could not be delivered.
+    @discardableResult
+    func signalForeground(_ signal: Int32) -> Bool {
+        guard let group = foregroundJobGroup else { return false }
+        return killpg(group, signal) == 0
+    }
+
+    /// True while a job other than the shell owns the terminal. Unlike
+    /// `isRunning` this is read live and also sees a command the shell
+    /// exec'd in place.
+    var hasForegroundJob: Bool {
+        foregroundJobGroup != nil
+    }
+
+    private var foregroundJobGroup: pid_t? {
+        guard shellPid > 0 else { return nil }
+        return Self.jobGroup(in: Self.processGroups(of: shellPid))
     }
 
     // MARK: - Process Inspection (nonisolated for testability)
@@ -184,6 +309,35 @@ final class TerminalViewModel {
         return executablePath(for: childPid)
     }
 
+    /// A process's own group and the foreground group of its controlling
+    /// terminal, read via libproc (the pty master fd is owned by Ghostty).
+    nonisolated static func processGroups(of pid: pid_t) -> (own: pid_t, terminalForeground: pid_t)? {
+        guard pid > 0 else { return nil }
+        var info = proc_bsdinfo()
+        let size = Int32(MemoryLayout<proc_bsdinfo>.size)
+        guard proc_pidinfo(pid, PROC_PIDTBSDINFO, 0, &info, size) == size else { return nil }
+        return (pid_t(info.pbi_pgid), pid_t(info.e_tpgid))
+    }
+
+    /// The terminal's foreground group when it is not the shell's own group.
+    nonisolated static func jobGroup(in groups: (own: pid_t, terminalForeground: pid_t)?) -> pid_t? {
+        guard let groups, groups.terminalForeground > 0,
+              groups.terminalForeground != groups.own else { return nil }
+        return groups.terminalForeground
+    }
+
+    /// Resolve a PID to its current working directory via libproc.
+    nonisolated static func workingDirectory(for pid: pid_t) -> String? {
+        guard pid > 0 else { return nil }
+        var info = proc_vnodepathinfo()
+        let size = Int32(MemoryLayout<proc_vnodepathinfo>.size)
+        guard proc_pidinfo(pid, PROC_PIDVNODEPATHINFO, 0, &info, size) == size else { return nil }
+        let path = withUnsafeBytes(of: info.pvi_cdir.vip_path) { raw in
+            String(cString: raw.bindMemory(to: CChar.self).baseAddress!)
+        }
+        return path.isEmpty ? nil : path
+    }
+
     /// Resolve a PID to its executable path via libproc.
     nonisolated static func executablePath(for pid: pid_t) -> String? {
         guard pid > 0 else { return nil }
@@ -5,7 +5,7 @@ import SwiftUI
 
 struct KanbanPanel: View {
     @Bindable var viewModel: GraphViewModel
-    @AppStorage("terminalColorScheme") private var schemeName: String = "hexed"
+    private var schemeName: String { ActiveColorScheme.shared.name }
     @State private var selectedIssueID: String?
 
     var body: some View {
@@ -7,6 +7,14 @@ struct AppearanceSettingsTab: View {
     @AppStorage("fontFamily") private var fontFamily: String = ""
     @AppStorage("terminalFontSize") private var terminalFontSize: Double = 14
     @AppStorage("terminalColorScheme") private var schemeName: String = "hexed"
+    @AppStorage("matchSystemAppearance") private var matchSystemAppearance = false
+    @AppStorage("terminalColorSchemeLight") private var lightSchemeName: String = "iTerm2 Solarized Light"
+    @AppStorage("terminalColorSchemeDark") private var darkSchemeName: String = "hexed"
+    @AppStorage("boldIsBright") private var boldIsBright = false
+    @AppStorage("minimumContrast") private var minimumContrast: Double = 1
+    @AppStorage("cursorColor") private var cursorColor: String = ""
+    @AppStorage("cursorStyle") private var cursorStyle: String = ""
+    @AppStorage("cursorBlink") private var cursorBlink: String = ""
     @AppStorage("uiFontSize") private var uiFontSize: Double = 12
     @AppStorage("paneFocusIndicator") private var paneFocusIndicator = true
     @AppStorage("dimUnfocusedPanes") private var dimUnfocusedPanes = true
@@ -36,7 +44,54 @@ struct AppearanceSettingsTab: View {
             }
 
             Section("Color Scheme") {
-                ThemeBrowser(selection: $schemeName)
+                Toggle("Match system appearance", isOn: $matchSystemAppearance)
+                if matchSystemAppearance {
+                    Picker("Light", selection: $lightSchemeName) {
+                        ForEach(ThemePreview.lightThemes) { theme in
+                            Text(theme.name).tag(theme.id)
+                        }
+                    }
+                    Picker("Dark", selection: $darkSchemeName) {
+                        Text(ThemePreview.hexed.name).tag(ThemePreview.hexed.id)
+                        Divider()
+                        ForEach(ThemePreview.darkThemes) { theme in
+                            Text(theme.name).tag(theme.id)
+                        }
+                    }
+                } else {
+                    ThemeBrowser(selection: $schemeName)
+                }
+                Toggle("Use bright colors for bold text", isOn: $boldIsBright)
+                Stepper(value: $minimumContrast, in: 1...21, step: 0.5) {
+                    HStack {
+                        Text("Minimum Contrast")
+                        Spacer()
+                        Text(minimumContrast > 1 ? String(format: "%.1f:1", minimumContrast) : "Off")
+                            .foregroundStyle(.secondary)
+                            .monospacedDigit()
+                    }
+                }
+            }
+
+            Section("Cursor") {
+                Picker("Style", selection: $cursorStyle) {
+                    Text("Config Default").tag("")
+                    Text("Block").tag("block")
+                    Text("Bar").tag("bar")
+                    Text("Underline").tag("underline")
+                    Text("Hollow Block").tag("block_hollow")
+                }
+                Picker("Blink", selection: $cursorBlink) {
+                    Text("Application Controlled").tag("")
+                    Text("Always").tag("true")
+                    Text("Never").tag("false")
+                }
+                HStack {
+                    ColorPicker("Color", selection: cursorColorBinding, supportsOpacity: false)
+                    if !cursorColor.isEmpty {
+                        Button("Use Theme Default") { cursorColor = "" }
+                    }
+                }
             }
 
             Section("UI") {
@@ -59,4 +114,18 @@ struct AppearanceSettingsTab: View {
         .formStyle(.grouped)
         .frame(width: 450)
     }
+
+    /// Bridges the stored hex string to ColorPicker. An empty string means
+    /// "follow the theme", shown as the current terminal foreground.
+    private var cursorColorBinding: Binding<Color> {
+        Binding(
+            get: {
+                if let rgb = RGB(hex: cursorColor) {
+                    return Color(nsColor: Palette.nsColor(rgb))
+                }
+                return Color(nsColor: GhosttyApp.foregroundColor)
+            },
+            set: { cursorColor = RGB(NSColor($0)).hexString }
+        )
+    }
 }
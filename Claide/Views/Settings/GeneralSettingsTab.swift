@@ -12,6 +12,10 @@ struct GeneralSettingsTab: View {
     @AppStorage("confirmBeforeClosing") private var confirmBeforeClosing = true
     @AppStorage("quitWhenLastWindowCloses") private var quitWhenLastWindowCloses = false
     @AppStorage("bellStyle") private var bellStyle: String = "visual"
+    @AppStorage("notifyOnLongCommands") private var notifyOnLongCommands = false
+    @AppStorage("longCommandThreshold") private var longCommandThreshold: Double = 30
+    @AppStorage("respawnPolicy") private var respawnPolicy: String = "never"
+    @AppStorage("respawnDelay") private var respawnDelay: Double = 1
 
     private var effectiveShell: String {
         ProcessInfo.processInfo.environment["SHELL"] ?? "/bin/zsh"
@@ -43,6 +47,24 @@ struct GeneralSettingsTab: View {
                         }
                     }
                 }
+
+                Picker("When Shell Exits", selection: $respawnPolicy) {
+                    Text("Keep Pane Open").tag("never")
+                    Text("Restart on Clean Exit").tag("cleanExit")
+                    Text("Always Restart").tag("always")
+                }
+
+                if respawnPolicy != "never" {
+                    Stepper(value: $respawnDelay, in: 1...30, step: 1) {
+                        HStack {
+                            Text("Restart Delay")
+                            Spacer()
+                            Text("\(Int(respawnDelay)) s")
+                                .foregroundStyle(.secondary)
+                                .monospacedDigit()
+                        }
+                    }
+                }
             }
 
             Section("Scrollback") {
@@ -77,6 +99,21 @@ struct GeneralSettingsTab: View {
                     Text("Audio").tag("audio")
                 }
             }
+
+            Section("Notifications") {
+                Toggle("Notify when long-running commands finish", isOn: $notifyOnLongCommands)
+                if notifyOnLongCommands {
+                    Stepper(value: $longCommandThreshold, in: 5...3600, step: 5) {
+                        HStack {
+                            Text("Minimum Duration")
+                            Spacer()
+                            Text("\(Int(longCommandThreshold)) s")
+                                .foregroundStyle(.secondary)
+                                .monospacedDigit()
+                        }
+                    }
+                }
+            }
         }
         .formStyle(.grouped)
         .frame(width: 450)
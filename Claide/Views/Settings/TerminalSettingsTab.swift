@@ -1,20 +1,66 @@
-// ABOUTME: Terminal settings tab for mouse behavior.
-// ABOUTME: Controls input behavior for all terminal panes.
+// ABOUTME: Terminal settings tab for shell, input, and clipboard behavior.
+// ABOUTME: Controls options that apply to all terminal panes.
 
 import SwiftUI
 
 struct TerminalSettingsTab: View {
+    @AppStorage("shellIntegration") private var shellIntegration: String = "detect"
+    @AppStorage("terminalType") private var terminalType: String = "xterm-ghostty"
+    @AppStorage("scrollToBottomOnKeystroke") private var scrollToBottomOnKeystroke: Bool = true
     @AppStorage("copyOnSelect") private var copyOnSelect: Bool = false
     @AppStorage("pasteOnRightClick") private var pasteOnRightClick: Bool = false
+    @AppStorage("trimTrailingSpaces") private var trimTrailingSpaces: Bool = true
 
     var body: some View {
         Form {
+            Section("Shell Integration") {
+                Picker("Integration", selection: $shellIntegration) {
+                    Text("Automatic").tag("detect")
+                    Text("Off").tag("none")
+                    Divider()
+                    ForEach(ShellKind.allCases.filter(\.supportsIntegration), id: \.self) { kind in
+                        Text(kind.displayName).tag(kind.rawValue)
+                    }
+                }
+                Text(integrationCaption)
+                    .font(.caption)
+                    .foregroundStyle(.secondary)
+            }
+
+            Section("Environment") {
+                Picker("TERM", selection: $terminalType) {
+                    Text("xterm-ghostty").tag("xterm-ghostty")
+                    Text("xterm-256color").tag("xterm-256color")
+                }
+                Text("Use xterm-256color if programs on remote hosts complain about an unknown terminal. Applies to new panes.")
+                    .font(.caption)
+                    .foregroundStyle(.secondary)
+            }
+
+            Section("Scrolling") {
+                Toggle("Scroll to bottom on keystroke", isOn: $scrollToBottomOnKeystroke)
+            }
+
             Section("Mouse") {
                 Toggle("Copy on select", isOn: $copyOnSelect)
                 Toggle("Paste on right-click", isOn: $pasteOnRightClick)
             }
+
+            Section("Clipboard") {
+                Toggle("Trim trailing spaces when copying", isOn: $trimTrailingSpaces)
+            }
         }
         .formStyle(.grouped)
         .frame(width: 450)
     }
+
+    private var integrationCaption: String {
+        let base = "Adds prompt marks and working directory reporting without editing shell startup files. Applies to new panes."
+        guard shellIntegration == "detect" else { return base }
+        let kind = ShellKind(executable: TerminalProfile.default.resolvedShell)
+        if kind.supportsIntegration {
+            return base + " Detected shell: \(kind.displayName)."
+        }
+        return base + " Your shell is not supported; choose one explicitly if it wraps a supported shell."
+    }
 }
@@ -8,7 +8,7 @@ struct SidebarSection: View {
     let tabManager: TerminalTabManager
     @State private var sidebarTab: SidebarTab = .board
     @AppStorage("fontFamily") private var fontFamily: String = ""
-    @AppStorage("terminalColorScheme") private var schemeName: String = "hexed"
+    private var schemeName: String { ActiveColorScheme.shared.name }
     @AppStorage("tasksExpanded") private var tasksExpanded = true
     @AppStorage("filesExpanded") private var filesExpanded = true
     @AppStorage("sidebarSplitRatio") private var storedSplitRatio: Double = 0.5
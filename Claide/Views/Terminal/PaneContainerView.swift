@@ -160,7 +160,7 @@ final class PaneTitleBar: NSView {
         setupTitleField()
         setupBorder()
 
-        let schemeName = UserDefaults.standard.string(forKey: "terminalColorScheme") ?? "hexed"
+        let schemeName = ActiveColorScheme.shared.name
         applyColorScheme(ChromeColorScheme.named(schemeName))
     }
 
@@ -5,7 +5,7 @@ import SwiftUI
 
 struct SessionStatusBar: View {
     let status: SessionStatus?
-    @AppStorage("terminalColorScheme") private var schemeName: String = "hexed"
+    private var schemeName: String { ActiveColorScheme.shared.name }
 
     private static let barHeight: CGFloat = 2
 
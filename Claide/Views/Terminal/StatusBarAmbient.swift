@@ -5,7 +5,7 @@ import SwiftUI
 
 struct StatusBarAmbient: View {
     let status: SessionStatus?
-    @AppStorage("terminalColorScheme") private var schemeName: String = "hexed"
+    private var schemeName: String { ActiveColorScheme.shared.name }
 
     var body: some View {
         let _ = schemeName
@@ -5,7 +5,7 @@ import SwiftUI
 
 struct StatusBarContextual: View {
     let status: SessionStatus?
-    @AppStorage("terminalColorScheme") private var schemeName: String = "hexed"
+    private var schemeName: String { ActiveColorScheme.shared.name }
     @State private var isHovering = false
 
     var body: some View {
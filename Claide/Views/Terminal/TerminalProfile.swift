@@ -30,8 +30,8 @@ struct TerminalProfile: Codable, Equatable {
     }
 
     /// Resolve the effective color scheme name.
-    var resolvedColorScheme: String {
-        colorScheme ?? UserDefaults.standard.string(forKey: "terminalColorScheme") ?? "hexed"
+    @MainActor var resolvedColorScheme: String {
+        colorScheme ?? ActiveColorScheme.shared.name
     }
 
     /// Resolve the effective font family.
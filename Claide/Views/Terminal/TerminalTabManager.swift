@@ -42,6 +42,11 @@ final class TerminalTabManager {
     private(set) var tabs: [Tab] = []
     var activeTabID: UUID?
 
+    /// True when any pane's terminal is owned by a job other than its shell.
+    var hasRunningProcess: Bool {
+        tabs.contains { $0.paneViewModels.values.contains { $0.hasForegroundJob } }
+    }
+
     /// Called after color scheme is applied to all panes, allowing the window
     /// controller to update window chrome (e.g. background color).
     var onColorSchemeApplied: (() -> Void)?
@@ -50,6 +55,9 @@ final class TerminalTabManager {
     private var lastDirectory: String?
     private var lastFontFamily: String = ""
 
+    /// Consecutive automatic restarts of shells that exited soon after launch.
+    private var rapidRespawns: [PaneID: Int] = [:]
+
     /// Maps tmux window IDs to Claide tab UUIDs for lifecycle tracking.
     private var tmuxWindowTabs: [Int: UUID] = [:]
 
@@ -653,6 +661,7 @@ final class TerminalTabManager {
             if tabs[index].paneController.closePane(paneID) {
                 closingView?.terminate()
                 tabs[index].paneViewModels.removeValue(forKey: paneID)
+                rapidRespawns.removeValue(forKey: paneID)
                 focusActiveTab()
             }
         } else {
@@ -660,6 +669,90 @@ final class TerminalTabManager {
         }
     }
 
+    /// Replace the active pane's shell with a fresh one in the same view,
+    /// keeping its place in the layout, profile, directory, and custom title.
+    func restartActivePane() {
+        guard let tab = activeTab else { return }
+        restartPane(tab.paneController.activePaneID, in: tab.id)
+    }
+
+    /// Replace a pane's shell with a fresh one in the same view.
+    func restartPane(_ paneID: PaneID, in tabID: UUID) {
+        guard let index = tabs.firstIndex(where: { $0.id == tabID }) else { return }
+        guard tmuxPaneID(for: paneID) == nil,
+              let view = tabs[index].paneController.paneView(for: paneID) as? GhosttyTerminalView,
+              let oldVM = tabs[index].paneViewModels[paneID] else { return }
+
+        let dir = oldVM.currentDirectory ?? lastDirectory ?? NSHomeDirectory()
+        oldVM.stopTrackingForeground()
+        view.terminate()
+
+        let vm = TerminalViewModel()
+        vm.customTitle = oldVM.customTitle
+        vm.tabColor = oldVM.tabColor
+        tabs[index].paneViewModels[paneID] = vm
+        setupPane(paneID: paneID, controller: tabs[index].paneController, view: view, viewModel: vm, directory: dir, environment: Self.buildEnvironment(), profile: oldVM.profile, sessionStatusVM: tabs[index].sessionStatusVM, fileLogVM: tabs[index].fileLogVM)
+
+        // The view keeps its frame and usually its first-responder status, so
+        // none of the callbacks that configure a new surface will fire.
+        view.syncSurfaceGeometry()
+        if view.window?.firstResponder === view {
+            view.setSurfaceFocused(true)
+        }
+        updateOcclusion()
+
+        if tabID == activeTabID {
+            focusActiveTab()
+        }
+
+        // Same nudge as addTab: zsh ignores SIGWINCH until TRAPWINCH is installed.
+        DispatchQueue.main.asyncAfter(deadline: .now() + 1.0) { [weak view] in
+            guard let view, view.frame.width > 0 else { return }
+            view.setFrameSize(view.frame.size)
+        }
+    }
+
+    /// Whether a shell that exited with `exitCode` should be restarted under
+    /// the given policy ("never", "cleanExit", or "always").
+    nonisolated static func shouldRespawn(policy: String, exitCode: Int32) -> Bool {
+        switch policy {
+        case "always": true
+        case "cleanExit": exitCode == 0
+        default: false
+        }
+    }
+
+    /// Delay before an automatic restart. Shells that keep exiting right after
+    /// launch back off exponentially so a broken shell can't spin in a loop.
+    nonisolated static func respawnDelay(base: TimeInterval, rapidFailures: Int) -> TimeInterval {
+        let delay = max(base, 1) * pow(2, Double(min(rapidFailures, 6)))
+        return min(delay, 60)
+    }
+
+    /// Restart an exited pane after the configured delay, unless it was
+    /// closed or restarted in the meantime.
+    private func scheduleRespawn(paneID: PaneID, controller: PaneTreeController, viewModel: TerminalViewModel) {
+        let defaults = UserDefaults.standard
+        let policy = defaults.string(forKey: "respawnPolicy") ?? "never"
+        guard !viewModel.isTmuxPane, policy != "never" else { return }
+
+        let livedBriefly = viewModel.launchedAt.map { Date().timeIntervalSince($0) < 10 } ?? true
+        let failures = livedBriefly ? rapidRespawns[paneID, default: 0] : 0
+        let delay = Self.respawnDelay(base: defaults.double(forKey: "respawnDelay"), rapidFailures: failures)
+
+        Task { @MainActor [weak self, weak viewModel, weak controller] in
+            try? await Task.sleep(for: .seconds(delay))
+            // Ghostty reports an exit twice (child-exited and close-surface);
+            // the first restart replaces the view model, which stops the second.
+            guard let self, let viewModel, let controller,
+                  let tab = self.tabs.first(where: { $0.paneController === controller }),
+                  tab.paneViewModels[paneID] === viewModel,
+                  Self.shouldRespawn(policy: policy, exitCode: viewModel.exitCode ?? 0) else { return }
+            self.rapidRespawns[paneID] = livedBriefly ? failures + 1 : 0
+            self.restartPane(paneID, in: tab.id)
+        }
+    }
+
     /// Toggle zoom on the active pane (maximize / restore).
     func toggleZoom() {
         guard let tab = activeTab else { return }
@@ -701,16 +794,12 @@ final class TerminalTabManager {
     ) {
         viewModel.profile = profile
         let shell = profile.resolvedShell
-
-        // Override SHELL if the profile specifies a custom shell.
-        // Ghostty reads SHELL to determine which shell to launch via login(1).
-        var env = environment
-        if profile.shell != nil {
-            env.append(("SHELL", shell))
-        }
+        let env = Self.paneEnvironment(environment, profile: profile, sessionID: viewModel.sessionID)
+        let workingDirectory = Self.existingDirectory(directory)
+        viewModel.currentDirectory = workingDirectory
 
         let pidsBefore = Self.childPids()
-        view.startShell(environment: env, directory: directory)
+        view.startShell(environment: env, directory: workingDirectory)
         viewModel.processStarted(executable: shell, args: [])
 
         // Detect the shell PID by diffing Claide's children before/after spawn.
@@ -745,8 +834,12 @@ final class TerminalTabManager {
         view.onDirectoryChange = { [weak viewModel] dir in
             viewModel?.directoryChanged(dir)
         }
-        view.onChildExit = { [weak viewModel] code in
-            viewModel?.processTerminated(exitCode: code)
+        view.onChildExit = { [weak self, weak viewModel, weak controller] code in
+            guard let viewModel else { return }
+            viewModel.processTerminated(exitCode: code)
+            if let controller {
+                self?.scheduleRespawn(paneID: paneID, controller: controller, viewModel: viewModel)
+            }
         }
         view.onBell = {
             let bellStyle = UserDefaults.standard.string(forKey: "bellStyle") ?? "visual"
@@ -764,19 +857,38 @@ final class TerminalTabManager {
                 }
             }
         }
+        viewModel.onCommandFinished = { [weak view] name, duration in
+            let defaults = UserDefaults.standard
+            guard defaults.bool(forKey: "notifyOnLongCommands"),
+                  duration >= defaults.double(forKey: "longCommandThreshold") else { return }
+            // Only notify when the user is looking elsewhere
+            if NSApp.isActive, view?.window?.isKeyWindow == true { return }
+            let formatter = DateComponentsFormatter()
+            formatter.unitsStyle = .abbreviated
+            formatter.allowedUnits = [.hour, .minute, .second]
+            let elapsed = formatter.string(from: duration) ?? "\(Int(duration))s"
+            GhosttyApp.postDesktopNotification(title: "\(name) finished", body: "Ran for \(elapsed)")
+        }
         view.onProgressReport = { [weak viewModel] state, progress in
             Task { @MainActor in
                 viewModel?.progressReported(state: state, progress: progress)
             }
         }
 
+        view.onColorChange = { [weak viewModel, weak controller] target, color in
+            guard let viewModel else { return }
+            viewModel.colorChanged(target, to: color)
+            if target == .foreground || target == .background {
+                controller?.containerView.applyColorScheme(viewModel.chromeColorScheme, for: paneID)
+            }
+        }
+
         view.onFocused = { [weak controller] in
             controller?.focusPane(paneID)
         }
 
         // Color scheme for pane dividers
-        let scheme = ChromeColorScheme.named(profile.resolvedColorScheme)
-        controller.containerView.applyColorScheme(scheme, for: paneID)
+        controller.containerView.applyColorScheme(viewModel.chromeColorScheme, for: paneID)
     }
 
     // MARK: - Color Scheme
@@ -784,8 +896,7 @@ final class TerminalTabManager {
     func applyColorSchemeToAll() {
         for tab in tabs {
             for (paneID, vm) in tab.paneViewModels {
-                let scheme = ChromeColorScheme.named(vm.profile.resolvedColorScheme)
-                tab.paneController.containerView.applyColorScheme(scheme, for: paneID)
+                tab.paneController.containerView.applyColorScheme(vm.chromeColorScheme, for: paneID)
             }
         }
         onColorSchemeApplied?()
@@ -862,13 +973,42 @@ final class TerminalTabManager {
         return pid
     }
 
+    /// Per-pane additions to the shared environment.
+    static func paneEnvironment(
+        _ environment: [(String, String)],
+        profile: TerminalProfile,
+        sessionID: UUID
+    ) -> [(String, String)] {
+        var env = environment
+        env.append(("TERM_SESSION_ID", sessionID.uuidString))
+        // Override SHELL if the profile specifies a custom shell.
+        // Ghostty reads SHELL to determine which shell to launch via login(1).
+        if profile.shell != nil {
+            env.append(("SHELL", profile.resolvedShell))
+        }
+        return env
+    }
+
+    /// The directory itself if it still exists, otherwise the home directory.
+    /// Restored sessions can point at folders deleted since the last launch,
+    /// and the shell would otherwise start in /.
+    static func existingDirectory(_ directory: String) -> String {
+        var isDirectory: ObjCBool = false
+        if FileManager.default.fileExists(atPath: directory, isDirectory: &isDirectory), isDirectory.boolValue {
+            return directory
+        }
+        return NSHomeDirectory()
+    }
+
     /// Claide-specific env vars to inject into child shells.
     /// Ghostty inherits the process environment automatically via getEnvMap();
     /// these are applied as overrides on top. The process environment is clean
     /// because Claide is launched via launchd (not `open`).
     static func buildEnvironment() -> [(String, String)] {
-        [
+        let version = Bundle.main.object(forInfoDictionaryKey: "CFBundleShortVersionString") as? String ?? "0"
+        return [
             ("TERM_PROGRAM", "Claide"),
+            ("TERM_PROGRAM_VERSION", version),
             ("SHELL_SESSIONS_DISABLE", "1"),
             ("CLAIDE_PID", "\(getpid())"),
         ]
@@ -9,7 +9,7 @@ struct TerminalSection: View {
     var showDragArea: Bool = true
     @AppStorage("fontFamily") private var fontFamily: String = ""
     // Cursor style is managed by Ghostty config (cursor-style, cursor-style-blink)
-    @AppStorage("terminalColorScheme") private var schemeName: String = "hexed"
+    private var schemeName: String { ActiveColorScheme.shared.name }
     @AppStorage("paneFocusIndicator") private var paneFocusIndicator = true
     @AppStorage("dimUnfocusedPanes") private var dimUnfocusedPanes = true
     @AppStorage("statusBarStyle") private var statusBarStyle: String = "ambient"
@@ -0,0 +1,49 @@
+// ABOUTME: Tests for resolving the terminal color scheme in effect.
+// ABOUTME: Verifies the light/dark pair and the mode reported via DEC mode 2031.
+
+import Testing
+import Foundation
+@testable import Claide
+
+@Suite("Active Color Scheme")
+struct ActiveColorSchemeTests {
+
+    private func makeDefaults() -> UserDefaults {
+        let suite = "ActiveColorSchemeTests-\(UUID().uuidString)"
+        let defaults = UserDefaults(suiteName: suite)!
+        defaults.removePersistentDomain(forName: suite)
+        defaults.set("Dracula", forKey: "terminalColorScheme")
+        defaults.set("iTerm2 Solarized Light", forKey: "terminalColorSchemeLight")
+        defaults.set("hexed", forKey: "terminalColorSchemeDark")
+        return defaults
+    }
+
+    @Test("Uses the chosen scheme when not matching the system")
+    func usesChosenScheme() {
+        let defaults = makeDefaults()
+
+        #expect(ActiveColorScheme.resolve(defaults: defaults, systemIsDark: true) == "Dracula")
+    }
+
+    @Test("Uses the light or dark pick when matching the system")
+    func usesAppearancePair() {
+        let defaults = makeDefaults()
+        defaults.set(true, forKey: "matchSystemAppearance")
+
+        #expect(ActiveColorScheme.resolve(defaults: defaults, systemIsDark: false) == "iTerm2 Solarized Light")
+        #expect(ActiveColorScheme.resolve(defaults: defaults, systemIsDark: true) == "hexed")
+    }
+
+    @Test("Reports the system mode while matching it")
+    func reportsSystemModeWhenMatching() {
+        let dark = ChromeColorScheme.hexed.background
+
+        #expect(!ActiveColorScheme.reportsDark(matchingSystem: true, systemIsDark: false, background: dark))
+    }
+
+    @Test("Reports the scheme's own brightness when not matching")
+    func reportsSchemeBrightness() {
+        #expect(ActiveColorScheme.reportsDark(matchingSystem: false, systemIsDark: false, background: ChromeColorScheme.hexed.background))
+        #expect(!ActiveColorScheme.reportsDark(matchingSystem: false, systemIsDark: true, background: RGB(0xfd, 0xf6, 0xe3)))
+    }
+}
@@ -1,5 +1,5 @@
 // ABOUTME: Tests for foreground process detection used by the tab bar process icon.
-// ABOUTME: Verifies child process discovery via libproc APIs.
+// ABOUTME: Verifies child process and working directory discovery via libproc APIs.
 
 import Testing
 import Foundation
@@ -36,6 +36,41 @@ struct ForegroundProcessTests {
         #expect(childPath?.hasSuffix("/sleep") == true)
     }
 
+    @Test("Resolves working directory for a known PID")
+    func resolvesWorkingDirectory() {
+        let cwd = TerminalViewModel.workingDirectory(for: getpid())
+        let expected = URL(fileURLWithPath: FileManager.default.currentDirectoryPath).resolvingSymlinksInPath().path
+        #expect(cwd.map { URL(fileURLWithPath: $0).resolvingSymlinksInPath().path } == expected)
+    }
+
+    @Test("Returns nil working directory for invalid PID")
+    func invalidPidHasNoWorkingDirectory() {
+        #expect(TerminalViewModel.workingDirectory(for: -1) == nil)
+    }
+
+    @Test("Resolves the process group for a known PID")
+    func resolvesProcessGroup() {
+        let groups = TerminalViewModel.processGroups(of: getpid())
+        #expect(groups?.own == getpgrp())
+    }
+
+    @Test("Returns no process groups for invalid PID")
+    func invalidPidHasNoProcessGroups() {
+        #expect(TerminalViewModel.processGroups(of: -1) == nil)
+    }
+
+    @Test("A foreground group other than the shell's is a job")
+    func foreignForegroundGroupIsJob() {
+        #expect(TerminalViewModel.jobGroup(in: (own: 100, terminalForeground: 200)) == 200)
+    }
+
+    @Test("The shell's own group or no terminal is not a job")
+    func shellGroupIsNotJob() {
+        #expect(TerminalViewModel.jobGroup(in: (own: 100, terminalForeground: 100)) == nil)
+        #expect(TerminalViewModel.jobGroup(in: (own: 100, terminalForeground: 0)) == nil)
+        #expect(TerminalViewModel.jobGroup(in: nil) == nil)
+    }
+
     @Test("Returns nil when parent has no children")
     func noChildrenReturnsNil() {
         let path = TerminalViewModel.foregroundChildExecutable(of: 99999)
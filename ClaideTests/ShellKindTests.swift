@@ -0,0 +1,36 @@
+// ABOUTME: Tests for shell flavor detection used by shell integration settings.
+// ABOUTME: Verifies executable name parsing and integration support flags.
+
+import Testing
+@testable import Claide
+
+@Suite("Shell Kind Detection")
+struct ShellKindTests {
+
+    @Test("Detects common shells from absolute paths")
+    func detectsFromPath() {
+        #expect(ShellKind(executable: "/bin/zsh") == .zsh)
+        #expect(ShellKind(executable: "/opt/homebrew/bin/bash") == .bash)
+        #expect(ShellKind(executable: "/opt/homebrew/bin/fish") == .fish)
+        #expect(ShellKind(executable: "/usr/local/bin/nu") == .nushell)
+    }
+
+    @Test("Strips the login shell dash prefix")
+    func stripsLoginPrefix() {
+        #expect(ShellKind(executable: "-zsh") == .zsh)
+    }
+
+    @Test("Unknown executables are classified as other")
+    func unknownIsOther() {
+        #expect(ShellKind(executable: "/usr/bin/true") == .other)
+        #expect(ShellKind(executable: "") == .other)
+    }
+
+    @Test("Only shells with bundled scripts support integration")
+    func integrationSupport() {
+        #expect(ShellKind.zsh.supportsIntegration)
+        #expect(ShellKind.elvish.supportsIntegration)
+        #expect(!ShellKind.nushell.supportsIntegration)
+        #expect(!ShellKind.other.supportsIntegration)
+    }
+}
@@ -146,6 +146,14 @@ struct TerminalTabManagerTests {
         #expect(value == "Claide")
     }
 
+    @Test("buildEnvironment sets TERM_PROGRAM_VERSION")
+    func buildEnvironmentSetsTermProgramVersion() {
+        let env = TerminalTabManager.buildEnvironment()
+
+        let value = env.first(where: { $0.0 == "TERM_PROGRAM_VERSION" })?.1
+        #expect(value?.isEmpty == false)
+    }
+
     @Test("buildEnvironment disables shell sessions")
     func buildEnvironmentDisablesShellSessions() {
         let env = TerminalTabManager.buildEnvironment()
@@ -154,6 +162,71 @@ struct TerminalTabManagerTests {
         #expect(value == "1")
     }
 
+    @Test("paneEnvironment exports the pane's session ID")
+    func paneEnvironmentExportsSessionID() {
+        let id = UUID()
+        let env = TerminalTabManager.paneEnvironment([], profile: .default, sessionID: id)
+
+        let value = env.first(where: { $0.0 == "TERM_SESSION_ID" })?.1
+        #expect(value == id.uuidString)
+    }
+
+    @Test("each pane gets a distinct session ID")
+    func panesHaveDistinctSessionIDs() {
+        let manager = makeManager(tabCount: 2)
+
+        #expect(manager.tabs[0].viewModel.sessionID != manager.tabs[1].viewModel.sessionID)
+    }
+
+    // MARK: - Working Directory
+
+    @Test("existingDirectory keeps a directory that exists")
+    func existingDirectoryKeepsValidPath() {
+        #expect(TerminalTabManager.existingDirectory("/tmp") == "/tmp")
+    }
+
+    @Test("existingDirectory falls back to home for missing or non-directory paths")
+    func existingDirectoryFallsBackToHome() {
+        #expect(TerminalTabManager.existingDirectory("/nonexistent/\(UUID().uuidString)") == NSHomeDirectory())
+        #expect(TerminalTabManager.existingDirectory("/etc/hosts") == NSHomeDirectory())
+    }
+
+    // MARK: - Restart
+
+    @Test("restartActivePane replaces the view model but keeps the view and custom title")
+    func restartKeepsViewAndTitle() {
+        let manager = makeManager()
+        let oldVM = manager.tabs[0].viewModel
+        let oldView = manager.tabs[0].terminalView
+        oldVM.customTitle = "build"
+
+        manager.restartActivePane()
+
+        #expect(manager.tabs[0].viewModel !== oldVM)
+        #expect(manager.tabs[0].viewModel.sessionID != oldVM.sessionID)
+        #expect(manager.tabs[0].viewModel.customTitle == "build")
+        #expect(manager.tabs[0].terminalView === oldView)
+    }
+
+    @Test("shouldRespawn follows the exit policy")
+    func respawnPolicy() {
+        #expect(!TerminalTabManager.shouldRespawn(policy: "never", exitCode: 0))
+        #expect(TerminalTabManager.shouldRespawn(policy: "cleanExit", exitCode: 0))
+        #expect(!TerminalTabManager.shouldRespawn(policy: "cleanExit", exitCode: 1))
+        #expect(TerminalTabManager.shouldRespawn(policy: "always", exitCode: 1))
+    }
+
+    @Test("respawnDelay enforces a one-second minimum")
+    func respawnDelayMinimum() {
+        #expect(TerminalTabManager.respawnDelay(base: 0, rapidFailures: 0) == 1)
+    }
+
+    @Test("respawnDelay backs off for shells that keep exiting, capped at a minute")
+    func respawnDelayBackoff() {
+        #expect(TerminalTabManager.respawnDelay(base: 1, rapidFailures: 3) == 8)
+        #expect(TerminalTabManager.respawnDelay(base: 2, rapidFailures: 10) == 60)
+    }
+
     // MARK: - Tab Identity
 
     @Test("each tab has a unique view model")
@@ -174,6 +247,16 @@ struct TerminalTabManagerTests {
         #expect(view0 !== view1)
     }
 
+    // MARK: - Busy State
+
+    @Test("hasRunningProcess is false when no pane has a foreground job")
+    func idlePanesAreNotBusy() {
+        let manager = makeManager(tabCount: 2)
+
+        manager.tabs[1].viewModel.isRunning = true
+        #expect(!manager.hasRunningProcess)
+    }
+
     // MARK: - Index-Based Switching
 
     @Test("switchToTab at valid index activates that tab")
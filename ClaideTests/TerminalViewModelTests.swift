@@ -1,5 +1,5 @@
-// ABOUTME: Tests for TerminalViewModel custom title and tab color behavior.
-// ABOUTME: Verifies displayTitle fallback and tabColor preset storage.
+// ABOUTME: Tests for TerminalViewModel state driven by terminal and process events.
+// ABOUTME: Feeds events through the public API and checks the observable results.
 
 import Testing
 import SwiftUI
@@ -51,6 +51,44 @@ struct TerminalViewModelTests {
     }
 }
 
+@Suite("Terminal View Model — Composite Title")
+@MainActor
+struct TerminalViewModelCompositeTitleTests {
+
+    @Test("composite title joins process and directory names")
+    func joinsProcessAndDirectory() {
+        let title = TerminalViewModel.compositeTitle(executable: "/usr/bin/vim", directory: "/tmp/project")
+
+        #expect(title == "vim — project")
+    }
+
+    @Test("composite title abbreviates the home directory")
+    func abbreviatesHome() {
+        let title = TerminalViewModel.compositeTitle(executable: "/bin/zsh", directory: NSHomeDirectory())
+
+        #expect(title == "zsh — ~")
+    }
+
+    @Test("directory change synthesizes a title when the program set none")
+    func directoryChangeSetsTitle() {
+        let vm = TerminalViewModel()
+        vm.processStarted(executable: "/bin/zsh", args: ["-l"])
+        vm.directoryChanged("file:///tmp/project")
+
+        #expect(vm.title == "zsh — project")
+    }
+
+    @Test("program title is not replaced by the composite title")
+    func programTitleWins() {
+        let vm = TerminalViewModel()
+        vm.processStarted(executable: "/bin/zsh", args: [])
+        vm.titleChanged("make test")
+        vm.directoryChanged("file:///tmp/project")
+
+        #expect(vm.title == "make test")
+    }
+}
+
 @Suite("Terminal View Model — Tab Color")
 @MainActor
 struct TerminalViewModelTabColorTests {
@@ -84,3 +122,95 @@ struct TerminalViewModelTabColorTests {
         }
     }
 }
+
+@Suite("Terminal View Model — Color Changes")
+@MainActor
+struct TerminalViewModelColorChangeTests {
+
+    @Test("background change is recorded as an override")
+    func backgroundChangeRecorded() {
+        let vm = TerminalViewModel()
+        vm.colorChanged(.background, to: RGB(0xff, 0xff, 0xff))
+
+        #expect(vm.backgroundOverride == RGB(0xff, 0xff, 0xff))
+        #expect(vm.chromeColorScheme.background == RGB(0xff, 0xff, 0xff))
+    }
+
+    @Test("a change back to the scheme's colors clears the override")
+    func resetToSchemeClearsOverride() {
+        let vm = TerminalViewModel()
+        let scheme = ChromeColorScheme.named(vm.profile.resolvedColorScheme)
+        vm.colorChanged(.foreground, to: RGB(0x01, 0x02, 0x03))
+        vm.colorChanged(.background, to: RGB(0x01, 0x02, 0x03))
+
+        vm.colorChanged(.foreground, to: scheme.foreground)
+        vm.colorChanged(.background, to: scheme.background)
+
+        #expect(vm.foregroundOverride == nil)
+        #expect(vm.backgroundOverride == nil)
+    }
+
+    @Test("palette and cursor changes leave chrome colors alone")
+    func paletteChangeIgnoredForChrome() {
+        let vm = TerminalViewModel()
+        vm.colorChanged(.palette(1), to: RGB(0x10, 0x20, 0x30))
+        vm.colorChanged(.cursor, to: RGB(0x10, 0x20, 0x30))
+
+        #expect(vm.foregroundOverride == nil)
+        #expect(vm.backgroundOverride == nil)
+    }
+}
+
+@Suite("Terminal View Model — Exit Status")
+@MainActor
+struct TerminalViewModelExitTests {
+
+    @Test("a failure exit code is not masked by a later zero")
+    func failureCodeWins() {
+        let vm = TerminalViewModel()
+        vm.processTerminated(exitCode: 2)
+        vm.processTerminated(exitCode: 0)
+
+        #expect(vm.exitCode == 2)
+    }
+
+    @Test("a failure exit code replaces an earlier zero")
+    func laterFailureRecorded() {
+        let vm = TerminalViewModel()
+        vm.processTerminated(exitCode: 0)
+        vm.processTerminated(exitCode: 130)
+
+        #expect(vm.exitCode == 130)
+    }
+}
+
+@Suite("Terminal View Model — Command Timing")
+@MainActor
+struct TerminalViewModelCommandTimingTests {
+
+    @Test("finishing a command reports its name and duration")
+    func finishReportsDuration() {
+        let vm = TerminalViewModel()
+        var finished: (String, TimeInterval)?
+        vm.onCommandFinished = { finished = ($0, $1) }
+
+        let start = Date(timeIntervalSince1970: 1_000)
+        vm.commandRunningChanged(true, executable: "/usr/bin/make", at: start)
+        vm.commandRunningChanged(false, executable: nil, at: start.addingTimeInterval(42))
+
+        #expect(finished?.0 == "make")
+        #expect(finished?.1 == 42)
+        #expect(vm.commandStartedAt == nil)
+    }
+
+    @Test("going idle without a tracked start does not report")
+    func idleWithoutStartIsSilent() {
+        let vm = TerminalViewModel()
+        var called = false
+        vm.onCommandFinished = { _, _ in called = true }
+
+        vm.commandRunningChanged(false, executable: nil)
+
+        #expect(!called)
+    }
+}